@@ -1,15 +1,21 @@
 use clap::Parser;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyModifiers,
+        MouseButton, MouseEvent, MouseEventKind,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::{
+    collections::{HashMap, HashSet},
     error::Error,
     fs::File,
     io::{self, BufReader},
-    path::Path,
+    path::{Path, PathBuf},
+    time::{Duration, Instant, SystemTime},
 };
 use tui::{
     backend::{Backend, CrosstermBackend},
@@ -70,27 +76,507 @@ struct OptionsChain {
     expirations: Vec<Expiration>,
 }
 
+// User-configurable color theme for moneyness, cursor, and header highlighting.
+// Field values are color names (see `parse_color`); missing fields in the
+// config file fall back to today's hardcoded colors.
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+struct Theme {
+    itm_color: String,
+    otm_color: String,
+    atm_color: String,
+    cursor_color: String,
+    header_color: String,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme {
+            itm_color: "green".to_string(),
+            otm_color: "red".to_string(),
+            atm_color: "yellow".to_string(),
+            cursor_color: "yellow".to_string(),
+            header_color: "green".to_string(),
+        }
+    }
+}
+
+// Theme resolved into actual `tui` colors, computed once at startup.
+struct ResolvedTheme {
+    itm: Color,
+    otm: Color,
+    atm: Color,
+    cursor: Color,
+    header: Color,
+}
+
+impl From<&Theme> for ResolvedTheme {
+    fn from(theme: &Theme) -> Self {
+        ResolvedTheme {
+            itm: parse_color(&theme.itm_color),
+            otm: parse_color(&theme.otm_color),
+            atm: parse_color(&theme.atm_color),
+            cursor: parse_color(&theme.cursor_color),
+            header: parse_color(&theme.header_color),
+        }
+    }
+}
+
+fn parse_color(name: &str) -> Color {
+    match name.to_lowercase().as_str() {
+        "black" => Color::Black,
+        "red" => Color::Red,
+        "green" => Color::Green,
+        "yellow" => Color::Yellow,
+        "blue" => Color::Blue,
+        "magenta" => Color::Magenta,
+        "cyan" => Color::Cyan,
+        "gray" | "grey" => Color::Gray,
+        "darkgray" | "darkgrey" => Color::DarkGray,
+        "lightred" => Color::LightRed,
+        "lightgreen" => Color::LightGreen,
+        "lightyellow" => Color::LightYellow,
+        "lightblue" => Color::LightBlue,
+        "lightmagenta" => Color::LightMagenta,
+        "lightcyan" => Color::LightCyan,
+        _ => Color::White,
+    }
+}
+
+// User-configurable keymap (action name -> key spec, e.g. "ctrl-d") plus the
+// color theme. Loaded from a TOML file so users can remap keys and
+// recolor the UI without recompiling.
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+struct Config {
+    keymap: HashMap<String, String>,
+    theme: Theme,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            keymap: default_keymap(),
+            theme: Theme::default(),
+        }
+    }
+}
+
+fn default_keymap() -> HashMap<String, String> {
+    let mut m = HashMap::new();
+    m.insert("quit".to_string(), "q".to_string());
+    m.insert("toggle_expiration".to_string(), "e".to_string());
+    m.insert("toggle_greeks".to_string(), "t".to_string());
+    m.insert("search".to_string(), "/".to_string());
+    m.insert("regex_search".to_string(), "?".to_string());
+    m.insert("next_match".to_string(), "n".to_string());
+    m.insert("prev_match".to_string(), "N".to_string());
+    m.insert("down".to_string(), "j".to_string());
+    m.insert("up".to_string(), "k".to_string());
+    m.insert("jump_first".to_string(), "g".to_string());
+    m.insert("jump_last".to_string(), "G".to_string());
+    m.insert("half_page_down".to_string(), "ctrl-d".to_string());
+    m.insert("half_page_up".to_string(), "ctrl-u".to_string());
+    m
+}
+
+// Parse a key spec like "q", "G", "ctrl-d", or "pagedown" into a crossterm
+// (KeyCode, KeyModifiers) pair.
+fn parse_key_spec(spec: &str) -> Option<(KeyCode, KeyModifiers)> {
+    let spec = spec.trim();
+    if let Some(rest) = spec.strip_prefix("ctrl-") {
+        let (code, _) = parse_key_spec(rest)?;
+        return Some((code, KeyModifiers::CONTROL));
+    }
+
+    let named = match spec.to_lowercase().as_str() {
+        "enter" => Some(KeyCode::Enter),
+        "esc" | "escape" => Some(KeyCode::Esc),
+        "up" => Some(KeyCode::Up),
+        "down" => Some(KeyCode::Down),
+        "pageup" => Some(KeyCode::PageUp),
+        "pagedown" => Some(KeyCode::PageDown),
+        "backspace" => Some(KeyCode::Backspace),
+        _ => None,
+    };
+    if let Some(code) = named {
+        return Some((code, KeyModifiers::NONE));
+    }
+
+    let mut chars = spec.chars();
+    let c = chars.next()?;
+    if chars.next().is_some() {
+        return None; // multi-char specs other than the named keys above aren't supported
+    }
+    Some((KeyCode::Char(c), KeyModifiers::NONE))
+}
+
+fn build_key_actions(keymap: &HashMap<String, String>) -> HashMap<(KeyCode, KeyModifiers), String> {
+    keymap
+        .iter()
+        .filter_map(|(action, spec)| parse_key_spec(spec).map(|binding| (binding, action.clone())))
+        .collect()
+}
+
+// Search $XDG_CONFIG_HOME, then next to the running binary, for a config
+// file, unless overridden by `--config`. Defaults reproduce today's behavior
+// when no config is found, so this is purely additive.
+fn load_config(args: &Args) -> Config {
+    let candidate_paths: Vec<PathBuf> = match &args.config {
+        Some(path) => vec![PathBuf::from(path)],
+        None => std::env::var("XDG_CONFIG_HOME")
+            .ok()
+            .map(|base| {
+                Path::new(&base)
+                    .join("options-chain-viewer")
+                    .join("config.toml")
+            })
+            .into_iter()
+            .chain(
+                std::env::current_exe()
+                    .ok()
+                    .and_then(|exe| exe.parent().map(|dir| dir.join("config.toml"))),
+            )
+            .collect(),
+    };
+
+    candidate_paths
+        .iter()
+        .find_map(|path| std::fs::read_to_string(path).ok())
+        .and_then(|contents| toml::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
 // App state
+// Vi-style input modes. `Normal` drives navigation motions, `Search` routes
+// keystrokes into the fuzzy filter query, and `RegexSearch` routes keystrokes
+// into the regex search pattern.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+enum Mode {
+    Normal,
+    Search,
+    RegexSearch,
+}
+
+// A cell in the options table that a regex search match can land on.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash)]
+enum MatchField {
+    CallSymbol,
+    PutSymbol,
+    Strike,
+}
+
+// A quote cell whose value can change between `--watch` reloads.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash)]
+enum CellField {
+    CallBid,
+    CallAsk,
+    PutBid,
+    PutAsk,
+}
+
+// How long a changed cell stays highlighted after a `--watch` reload.
+const FLASH_DURATION: Duration = Duration::from_millis(1500);
+
+// Approximate number of expiration rows visible at once.
+const VISIBLE_ITEMS: usize = 10;
+
 struct App {
     options_chain: OptionsChain,
     expanded_expirations: Vec<bool>, // Track which expirations are expanded
     cursor_position: usize,          // Current cursor position
     scroll_offset: usize,            // Scroll offset for viewing expirations
     show_greeks: bool,               // Toggle to show/hide greeks
+    mode: Mode,                      // Current input mode
+    query: String,                   // Current fuzzy filter query
+    filtered: Vec<Vec<usize>>,       // Matching OptionPair indices per expiration
+    pending_count: String,           // Digits typed so far for a motion count prefix
+    expiration_hits: Vec<(Rect, usize)>, // Screen rects for expiration header rows, from the last render
+    option_hits: Vec<(Rect, usize, usize)>, // Screen rects for option rows (expiration idx, option idx)
+    key_actions: HashMap<(KeyCode, KeyModifiers), String>, // Resolved from Config.keymap
+    theme: ResolvedTheme,                   // Resolved from Config.theme
+    search_pattern: String,                 // Pattern typed so far in RegexSearch mode
+    compiled_regex: Option<Regex>,          // Successfully compiled pattern, if any
+    search_error: Option<String>,           // Compile error for an invalid pattern
+    search_matches: Vec<(usize, usize, MatchField)>, // Ordered (expiration idx, option idx, field) hits
+    search_match_set: HashSet<(usize, usize, MatchField)>, // Same hits, for O(1) lookup while rendering
+    current_match: usize,                                  // Index into search_matches for n/N
+    cell_flash: HashMap<(usize, usize, CellField), Instant>, // Last-changed time per quote cell, from --watch reloads
 }
 
 impl App {
-    fn new(options_chain: OptionsChain) -> Self {
+    fn new(options_chain: OptionsChain, config: Config) -> Self {
         let expiration_count = options_chain.expirations.len();
+        let filtered = options_chain
+            .expirations
+            .iter()
+            .map(|exp| (0..exp.options.len()).collect())
+            .collect();
+        let key_actions = build_key_actions(&config.keymap);
+        let theme = ResolvedTheme::from(&config.theme);
         App {
             options_chain,
             expanded_expirations: vec![false; expiration_count], // Start with all collapsed
             cursor_position: 0,
             scroll_offset: 0,
             show_greeks: true,
+            mode: Mode::Normal,
+            query: String::new(),
+            filtered,
+            pending_count: String::new(),
+            expiration_hits: Vec::new(),
+            option_hits: Vec::new(),
+            key_actions,
+            theme,
+            search_pattern: String::new(),
+            compiled_regex: None,
+            search_error: None,
+            search_matches: Vec::new(),
+            search_match_set: HashSet::new(),
+            current_match: 0,
+            cell_flash: HashMap::new(),
+        }
+    }
+
+    // Find the expiration index whose header rect contains (column, row).
+    fn hit_test_expiration(&self, column: u16, row: u16) -> Option<usize> {
+        self.expiration_hits
+            .iter()
+            .find(|(rect, _)| rect_contains(rect, column, row))
+            .map(|(_, idx)| *idx)
+    }
+
+    // Find the (expiration idx, option idx) whose row rect contains (column, row).
+    fn hit_test_option(&self, column: u16, row: u16) -> Option<(usize, usize)> {
+        self.option_hits
+            .iter()
+            .find(|(rect, _, _)| rect_contains(rect, column, row))
+            .map(|(_, exp_idx, opt_idx)| (*exp_idx, *opt_idx))
+    }
+
+    fn toggle_expiration(&mut self, idx: usize) {
+        if idx < self.expanded_expirations.len() {
+            self.expanded_expirations[idx] = !self.expanded_expirations[idx];
+        }
+    }
+
+    fn select_expiration(&mut self, idx: usize) {
+        if idx < self.expanded_expirations.len() {
+            self.cursor_position = idx;
+            self.adjust_scroll();
+        }
+    }
+
+    fn enter_search_mode(&mut self) {
+        self.mode = Mode::Search;
+    }
+
+    fn exit_search_mode(&mut self) {
+        self.mode = Mode::Normal;
+    }
+
+    fn enter_regex_search(&mut self) {
+        self.mode = Mode::RegexSearch;
+    }
+
+    fn push_search_char(&mut self, c: char) {
+        self.search_pattern.push(c);
+    }
+
+    fn pop_search_char(&mut self) {
+        self.search_pattern.pop();
+    }
+
+    // Leave RegexSearch mode without touching the last compiled pattern or matches.
+    fn cancel_regex_search(&mut self) {
+        self.mode = Mode::Normal;
+    }
+
+    // Compile the typed pattern and recompute matches. Only called when the
+    // pattern changes (on Enter), never on every keystroke or render.
+    fn commit_regex_search(&mut self) {
+        self.mode = Mode::Normal;
+
+        if self.search_pattern.is_empty() {
+            self.compiled_regex = None;
+            self.search_error = None;
+            self.search_matches.clear();
+            self.search_match_set.clear();
+            return;
+        }
+
+        match Regex::new(&self.search_pattern) {
+            Ok(re) => {
+                self.compiled_regex = Some(re);
+                self.search_error = None;
+                self.recompute_search_matches();
+                self.current_match = 0;
+                self.jump_to_current_match();
+            }
+            Err(e) => {
+                self.compiled_regex = None;
+                self.search_error = Some(e.to_string());
+                self.search_matches.clear();
+                self.search_match_set.clear();
+            }
+        }
+    }
+
+    // Re-scan the whole chain for matches against the compiled regex. Called
+    // whenever the pattern changes or new data is loaded, never per-render.
+    fn recompute_search_matches(&mut self) {
+        self.search_matches.clear();
+        self.search_match_set.clear();
+
+        let re = match &self.compiled_regex {
+            Some(re) => re,
+            None => return,
+        };
+
+        for (exp_idx, expiration) in self.options_chain.expirations.iter().enumerate() {
+            for (opt_idx, option) in expiration.options.iter().enumerate() {
+                if re.is_match(&option.call.symbol) {
+                    self.search_matches
+                        .push((exp_idx, opt_idx, MatchField::CallSymbol));
+                }
+                if re.is_match(&option.put.symbol) {
+                    self.search_matches
+                        .push((exp_idx, opt_idx, MatchField::PutSymbol));
+                }
+                if re.is_match(&format!("{:.2}", option.strike)) {
+                    self.search_matches
+                        .push((exp_idx, opt_idx, MatchField::Strike));
+                }
+            }
+        }
+
+        self.search_match_set = self.search_matches.iter().copied().collect();
+    }
+
+    // Expand the expiration containing the current match and scroll it into view.
+    fn jump_to_current_match(&mut self) {
+        if let Some(&(exp_idx, ..)) = self.search_matches.get(self.current_match) {
+            // Only the expiration holding the current match stays expanded.
+            // Leaving every previously-visited one open as n/N cycles through
+            // matches can expand far more rows than the terminal can show,
+            // which overflows the table layout.
+            for expanded in self.expanded_expirations.iter_mut() {
+                *expanded = false;
+            }
+            if exp_idx < self.expanded_expirations.len() {
+                self.expanded_expirations[exp_idx] = true;
+            }
+            self.cursor_position = exp_idx;
+            self.adjust_scroll();
+        }
+    }
+
+    fn next_match(&mut self) {
+        if self.search_matches.is_empty() {
+            return;
+        }
+        self.current_match = (self.current_match + 1) % self.search_matches.len();
+        self.jump_to_current_match();
+    }
+
+    fn prev_match(&mut self) {
+        if self.search_matches.is_empty() {
+            return;
+        }
+        self.current_match =
+            (self.current_match + self.search_matches.len() - 1) % self.search_matches.len();
+        self.jump_to_current_match();
+    }
+
+    // Append a digit to the pending motion count (e.g. the '5' in "5j").
+    fn push_count_digit(&mut self, d: char) {
+        self.pending_count.push(d);
+    }
+
+    // Consume and return the pending count (defaulting to 1), clearing the buffer.
+    fn take_count(&mut self) -> usize {
+        let count = self.pending_count.parse().unwrap_or(1);
+        self.pending_count.clear();
+        count
+    }
+
+    fn jump_to_first(&mut self) {
+        if !self.expanded_expirations.is_empty() {
+            self.cursor_position = 0;
+            self.adjust_scroll();
+        }
+    }
+
+    fn jump_to_last(&mut self) {
+        if !self.expanded_expirations.is_empty() {
+            self.cursor_position = self.expanded_expirations.len() - 1;
+            self.adjust_scroll();
+        }
+    }
+
+    fn half_page_down(&mut self) {
+        if !self.expanded_expirations.is_empty() {
+            self.cursor_position = std::cmp::min(
+                self.cursor_position + 5,
+                self.expanded_expirations.len() - 1,
+            );
+            self.adjust_scroll();
         }
     }
 
+    fn half_page_up(&mut self) {
+        if !self.expanded_expirations.is_empty() {
+            self.cursor_position = self.cursor_position.saturating_sub(5);
+            self.adjust_scroll();
+        }
+    }
+
+    fn push_query_char(&mut self, c: char) {
+        self.query.push(c);
+        self.recompute_filter();
+    }
+
+    fn pop_query_char(&mut self) {
+        self.query.pop();
+        self.recompute_filter();
+    }
+
+    // Recompute, for every expiration, which OptionPair indices match the
+    // current query, sorted by descending fuzzy score (closest strikes first).
+    fn recompute_filter(&mut self) {
+        if self.query.is_empty() {
+            self.filtered = self
+                .options_chain
+                .expirations
+                .iter()
+                .map(|exp| (0..exp.options.len()).collect())
+                .collect();
+            return;
+        }
+
+        self.filtered = self
+            .options_chain
+            .expirations
+            .iter()
+            .map(|exp| {
+                let mut scored: Vec<(usize, i64)> = exp
+                    .options
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, option)| {
+                        let candidate = format!(
+                            "{} {} {:.2}",
+                            option.call.symbol, option.put.symbol, option.strike
+                        );
+                        fuzzy_score(&self.query, &candidate).map(|score| (i, score))
+                    })
+                    .collect();
+                scored.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
+                scored.into_iter().map(|(i, _)| i).collect()
+            })
+            .collect();
+    }
+
     fn toggle_current_expiration(&mut self) {
         if self.cursor_position < self.expanded_expirations.len() {
             self.expanded_expirations[self.cursor_position] =
@@ -139,15 +625,163 @@ impl App {
 
     // Adjust scroll offset to keep cursor visible
     fn adjust_scroll(&mut self) {
-        // Keep cursor within visible area (assuming ~10 visible items)
-        const VISIBLE_ITEMS: usize = 10;
-
         if self.cursor_position < self.scroll_offset {
             self.scroll_offset = self.cursor_position;
         } else if self.cursor_position >= self.scroll_offset + VISIBLE_ITEMS {
             self.scroll_offset = self.cursor_position - VISIBLE_ITEMS + 1;
         }
     }
+
+    // Move the scroll offset by `delta` independent of the cursor, clamping
+    // to the valid range. Used by the mouse wheel: unlike keyboard motions,
+    // wheel scrolling doesn't move the cursor, so calling `adjust_scroll`
+    // here would just snap the offset right back to the cursor's position.
+    fn scroll_by(&mut self, delta: i64) {
+        let expiration_count = self.expanded_expirations.len();
+        let max_offset = expiration_count.saturating_sub(VISIBLE_ITEMS);
+        let new_offset = (self.scroll_offset as i64 + delta).clamp(0, max_offset as i64);
+        self.scroll_offset = new_offset as usize;
+    }
+
+    fn is_flashing(&self, key: (usize, usize, CellField)) -> bool {
+        matches!(self.cell_flash.get(&key), Some(t) if t.elapsed() < FLASH_DURATION)
+    }
+
+    // Replace `options_chain` with a freshly reloaded one from `--watch`,
+    // carrying over UI state that would otherwise jump: expirations are
+    // matched up by `date` so `expanded_expirations` and the cursor stick to
+    // the same expiration even if rows are inserted, removed, or reordered,
+    // and any bid/ask that moved is flagged to flash briefly.
+    fn merge_chain(&mut self, new_chain: OptionsChain) {
+        let cursor_date = self
+            .options_chain
+            .expirations
+            .get(self.cursor_position)
+            .map(|exp| exp.date.clone());
+
+        let mut new_expanded = Vec::with_capacity(new_chain.expirations.len());
+        let mut new_flash = HashMap::new();
+
+        for (new_idx, new_exp) in new_chain.expirations.iter().enumerate() {
+            let old_match = self
+                .options_chain
+                .expirations
+                .iter()
+                .enumerate()
+                .find(|(_, old_exp)| old_exp.date == new_exp.date);
+
+            new_expanded.push(
+                old_match
+                    .map(|(old_idx, _)| self.expanded_expirations[old_idx])
+                    .unwrap_or(false),
+            );
+
+            let Some((old_idx, old_exp)) = old_match else {
+                continue;
+            };
+            for (new_opt_idx, new_opt) in new_exp.options.iter().enumerate() {
+                let Some((old_opt_idx, old_opt)) = old_exp
+                    .options
+                    .iter()
+                    .enumerate()
+                    .find(|(_, opt)| opt.strike == new_opt.strike)
+                else {
+                    continue;
+                };
+
+                // A cell that changed on an earlier reload keeps its original
+                // timestamp, so its flash runs for the full FLASH_DURATION.
+                let mut flag = |changed: bool, field: CellField| {
+                    if changed {
+                        new_flash.insert((new_idx, new_opt_idx, field), Instant::now());
+                    } else if let Some(&t) = self.cell_flash.get(&(old_idx, old_opt_idx, field)) {
+                        new_flash.insert((new_idx, new_opt_idx, field), t);
+                    }
+                };
+                flag(old_opt.call.bid != new_opt.call.bid, CellField::CallBid);
+                flag(old_opt.call.ask != new_opt.call.ask, CellField::CallAsk);
+                flag(old_opt.put.bid != new_opt.put.bid, CellField::PutBid);
+                flag(old_opt.put.ask != new_opt.put.ask, CellField::PutAsk);
+            }
+        }
+
+        self.options_chain = new_chain;
+        self.expanded_expirations = new_expanded;
+        self.cell_flash = new_flash;
+
+        if let Some(date) = cursor_date {
+            if let Some(new_idx) = self
+                .options_chain
+                .expirations
+                .iter()
+                .position(|exp| exp.date == date)
+            {
+                self.cursor_position = new_idx;
+            }
+        }
+        self.cursor_position = self
+            .cursor_position
+            .min(self.expanded_expirations.len().saturating_sub(1));
+        self.adjust_scroll();
+
+        self.recompute_filter();
+        self.recompute_search_matches();
+        self.current_match = self
+            .current_match
+            .min(self.search_matches.len().saturating_sub(1));
+    }
+}
+
+// Skim-style fuzzy matcher: every query char must appear in `candidate`, in
+// order, case-insensitively. Returns a score rewarding consecutive matches
+// and matches that land on a word boundary (start of string, after '-', or
+// a digit->letter transition), or `None` if the query doesn't match at all.
+fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let query_chars: Vec<char> = query.to_lowercase().chars().collect();
+    let candidate_chars: Vec<char> = candidate.to_lowercase().chars().collect();
+
+    let mut score: i64 = 0;
+    let mut qi = 0;
+    let mut prev_matched_ci: Option<usize> = None;
+
+    for (ci, &c) in candidate_chars.iter().enumerate() {
+        if qi >= query_chars.len() {
+            break;
+        }
+        if c != query_chars[qi] {
+            continue;
+        }
+
+        score += 1;
+
+        if prev_matched_ci == Some(ci.wrapping_sub(1)) {
+            score += 5; // consecutive match bonus
+        }
+
+        let at_boundary = ci == 0
+            || candidate_chars[ci - 1] == '-'
+            || (candidate_chars[ci - 1].is_ascii_digit() && c.is_alphabetic());
+        if at_boundary {
+            score += 3;
+        }
+
+        prev_matched_ci = Some(ci);
+        qi += 1;
+    }
+
+    if qi == query_chars.len() {
+        Some(score)
+    } else {
+        None
+    }
+}
+
+fn rect_contains(rect: &Rect, column: u16, row: u16) -> bool {
+    column >= rect.x && column < rect.x + rect.width && row >= rect.y && row < rect.y + rect.height
 }
 
 fn read_options_chain<P: AsRef<Path>>(path: P) -> Result<OptionsChain, Box<dyn Error>> {
@@ -160,6 +794,12 @@ fn read_options_chain<P: AsRef<Path>>(path: P) -> Result<OptionsChain, Box<dyn E
     Ok(options_chain)
 }
 
+// The file's last-modified time, used by `--watch` to detect a rewrite.
+// `None` if the file is (momentarily) missing or the platform can't report it.
+fn file_modified<P: AsRef<Path>>(path: P) -> Option<SystemTime> {
+    std::fs::metadata(path).ok()?.modified().ok()
+}
+
 /// Command line arguments
 #[derive(Parser, Debug)]
 #[clap(author, version, about = "Options chain viewer")]
@@ -167,6 +807,154 @@ struct Args {
     /// Path to the options chain JSON file
     #[clap(default_value = "sample-options-chain.json")]
     filename: String,
+
+    /// Path to a config file for keybindings and colors (overrides the
+    /// default search in $XDG_CONFIG_HOME and next to the binary)
+    #[clap(long)]
+    config: Option<String>,
+
+    /// Poll the input file for changes and reload it live, for a feed that
+    /// rewrites the file in place
+    #[clap(long)]
+    watch: bool,
+}
+
+// Dispatch a single key event to the app, split into a Normal/Search modal
+// layer. Returns true if the app should quit.
+fn handle_key(app: &mut App, key: KeyEvent) -> bool {
+    match app.mode {
+        Mode::Search => {
+            match key.code {
+                KeyCode::Esc | KeyCode::Enter => app.exit_search_mode(),
+                KeyCode::Backspace => app.pop_query_char(),
+                KeyCode::Char(c) => app.push_query_char(c),
+                _ => {}
+            }
+            false
+        }
+        Mode::RegexSearch => {
+            match key.code {
+                KeyCode::Enter => app.commit_regex_search(),
+                KeyCode::Esc => app.cancel_regex_search(),
+                KeyCode::Backspace => app.pop_search_char(),
+                KeyCode::Char(c) => app.push_search_char(c),
+                _ => {}
+            }
+            false
+        }
+        Mode::Normal => {
+            // Digits (other than a leading '0') accumulate into a pending
+            // count prefix, e.g. "5j", rather than triggering a motion.
+            if let KeyCode::Char(d @ '1'..='9') = key.code {
+                app.push_count_digit(d);
+                return false;
+            }
+            if let KeyCode::Char('0') = key.code {
+                if !app.pending_count.is_empty() {
+                    app.push_count_digit('0');
+                    return false;
+                }
+            }
+
+            // Resolve the key against the user's (possibly remapped) keymap first.
+            // Shifted characters such as 'G' arrive with SHIFT set, which the
+            // character itself already encodes, so it is ignored for Char keys.
+            let modifiers = match key.code {
+                KeyCode::Char(_) => key.modifiers - KeyModifiers::SHIFT,
+                _ => key.modifiers,
+            };
+            if let Some(action) = app.key_actions.get(&(key.code, modifiers)).cloned() {
+                match action.as_str() {
+                    "quit" => return true,
+                    "toggle_expiration" => app.toggle_current_expiration(),
+                    "toggle_greeks" => app.toggle_greeks(),
+                    "search" => app.enter_search_mode(),
+                    "regex_search" => app.enter_regex_search(),
+                    "next_match" => app.next_match(),
+                    "prev_match" => app.prev_match(),
+                    "jump_first" => {
+                        app.take_count();
+                        app.jump_to_first();
+                    }
+                    "jump_last" => {
+                        app.take_count();
+                        app.jump_to_last();
+                    }
+                    "down" => {
+                        let count = app.take_count();
+                        for _ in 0..count {
+                            app.move_cursor_down();
+                        }
+                    }
+                    "up" => {
+                        let count = app.take_count();
+                        for _ in 0..count {
+                            app.move_cursor_up();
+                        }
+                    }
+                    "half_page_down" => {
+                        app.take_count();
+                        app.half_page_down();
+                    }
+                    "half_page_up" => {
+                        app.take_count();
+                        app.half_page_up();
+                    }
+                    _ => {}
+                }
+                return false;
+            }
+
+            // Keys outside the keymap keep their fixed behavior: Enter always
+            // doubles as expand/collapse, and arrow/paging keys always navigate.
+            match key.code {
+                KeyCode::Enter => app.toggle_current_expiration(),
+                KeyCode::Down => {
+                    let count = app.take_count();
+                    for _ in 0..count {
+                        app.move_cursor_down();
+                    }
+                }
+                KeyCode::Up => {
+                    let count = app.take_count();
+                    for _ in 0..count {
+                        app.move_cursor_up();
+                    }
+                }
+                KeyCode::PageDown => {
+                    app.take_count();
+                    app.page_down();
+                }
+                KeyCode::PageUp => {
+                    app.take_count();
+                    app.page_up();
+                }
+                _ => {}
+            }
+            false
+        }
+    }
+}
+
+// Dispatch a mouse event against the hit-test map recorded on the last render:
+// clicking an expiration header toggles it, clicking an option row selects
+// its expiration, and the wheel scrolls.
+fn handle_mouse(app: &mut App, mouse: MouseEvent) {
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) => {
+            // Option rows take priority: the header/table hit-rects are
+            // mutually exclusive, but checking rows first keeps a click on
+            // a strike selecting it even if that ever stops being true.
+            if let Some((exp_idx, _option_idx)) = app.hit_test_option(mouse.column, mouse.row) {
+                app.select_expiration(exp_idx);
+            } else if let Some(exp_idx) = app.hit_test_expiration(mouse.column, mouse.row) {
+                app.toggle_expiration(exp_idx);
+            }
+        }
+        MouseEventKind::ScrollUp => app.scroll_by(-1),
+        MouseEventKind::ScrollDown => app.scroll_by(1),
+        _ => {}
+    }
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
@@ -176,6 +964,9 @@ fn main() -> Result<(), Box<dyn Error>> {
     // Read the options chain from the specified JSON file
     let options_chain = read_options_chain(&args.filename)?;
 
+    // Load keybindings and color theme, falling back to today's defaults
+    let config = load_config(&args);
+
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -184,24 +975,39 @@ fn main() -> Result<(), Box<dyn Error>> {
     let mut terminal = Terminal::new(backend)?;
 
     // Create app state
-    let mut app = App::new(options_chain);
+    let mut app = App::new(options_chain, config);
+
+    // In --watch mode poll frequently so file changes are picked up
+    // promptly; otherwise poll with a long timeout so the loop is
+    // effectively a blocking read, as before.
+    let poll_timeout = if args.watch {
+        Duration::from_millis(250)
+    } else {
+        Duration::from_secs(3600)
+    };
+    let mut last_modified = file_modified(&args.filename);
 
     // Main loop
     loop {
-        terminal.draw(|f| ui(f, &app))?;
+        terminal.draw(|f| ui(f, &mut app))?;
 
-        if let Event::Key(key) = event::read()? {
-            match key.code {
-                KeyCode::Char('q') => break,
-                KeyCode::Char('e') | KeyCode::Enter => app.toggle_current_expiration(),
-                KeyCode::Char('g') => app.toggle_greeks(),
-                KeyCode::Down => app.move_cursor_down(),
-                KeyCode::Up => app.move_cursor_up(),
-                KeyCode::PageDown => app.page_down(),
-                KeyCode::PageUp => app.page_up(),
+        if event::poll(poll_timeout)? {
+            match event::read()? {
+                Event::Key(key) if handle_key(&mut app, key) => break,
+                Event::Mouse(mouse) => handle_mouse(&mut app, mouse),
                 _ => {}
             }
         }
+
+        if args.watch {
+            let modified = file_modified(&args.filename);
+            if modified.is_some() && modified != last_modified {
+                last_modified = modified;
+                if let Ok(new_chain) = read_options_chain(&args.filename) {
+                    app.merge_chain(new_chain);
+                }
+            }
+        }
     }
 
     // Cleanup terminal
@@ -216,7 +1022,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-fn ui<B: Backend>(f: &mut Frame<B>, app: &App) {
+fn ui<B: Backend>(f: &mut Frame<B>, app: &mut App) {
     let size = f.size();
 
     // Create main layout with just a title and content area
@@ -227,44 +1033,94 @@ fn ui<B: Backend>(f: &mut Frame<B>, app: &App) {
         .split(size);
 
     // Render title block
-    let title_block = Block::default()
-        .borders(Borders::ALL)
-        .title(format!(
-            "{} - ${:.2} - {} - Use ↑/↓/PgUp/PgDn to navigate, 'e' to expand/collapse, 'g' to toggle Greeks",
+    let title = if app.mode == Mode::RegexSearch {
+        format!(
+            "{} - ${:.2} - {} - Search (regex): {}_",
+            app.options_chain.symbol,
+            app.options_chain.last_price,
+            app.options_chain.last_update,
+            app.search_pattern
+        )
+    } else if let Some(err) = &app.search_error {
+        format!(
+            "{} - ${:.2} - {} - Invalid regex: {}",
+            app.options_chain.symbol,
+            app.options_chain.last_price,
+            app.options_chain.last_update,
+            err
+        )
+    } else if app.mode == Mode::Search || !app.query.is_empty() {
+        format!(
+            "{} - ${:.2} - {} - Filter: {}_",
+            app.options_chain.symbol,
+            app.options_chain.last_price,
+            app.options_chain.last_update,
+            app.query
+        )
+    } else if !app.search_matches.is_empty() {
+        format!(
+            "{} - ${:.2} - {} - Match {}/{} for '{}' ('n'/'N' to jump)",
+            app.options_chain.symbol,
+            app.options_chain.last_price,
+            app.options_chain.last_update,
+            app.current_match + 1,
+            app.search_matches.len(),
+            app.search_pattern
+        )
+    } else {
+        format!(
+            "{} - ${:.2} - {} - Use j/k/g/G/Ctrl-d/Ctrl-u to navigate, 'e' to expand/collapse, 't' to toggle Greeks, '/' to filter, '?' to search",
             app.options_chain.symbol, app.options_chain.last_price, app.options_chain.last_update
-        ));
+        )
+    };
+    let title_block = Block::default().borders(Borders::ALL).title(title);
     f.render_widget(title_block, chunks[0]);
 
     // Render all expirations in the main area
     render_expirations_list(f, app, chunks[1]);
 }
 
-fn render_expirations_list<B: Backend>(f: &mut Frame<B>, app: &App, area: Rect) {
-    let expirations = &app.options_chain.expirations;
+fn render_expirations_list<B: Backend>(f: &mut Frame<B>, app: &mut App, area: Rect) {
+    app.expiration_hits.clear();
+    app.option_hits.clear();
+
+    let expiration_count = app.options_chain.expirations.len();
 
     // Determine visible range based on scroll offset
     let visible_start = app.scroll_offset;
-    let visible_end = std::cmp::min(expirations.len(), visible_start + (area.height as usize));
 
-    // Calculate heights for visible expirations
+    // Calculate heights for visible expirations, stopping once their combined
+    // minimum height fills the available area. Expanded expirations can each
+    // need far more than one row, so bounding this by row budget (rather than
+    // by item count, as if every expiration were a single line) keeps the
+    // sum of constraints from exceeding `area.height` — otherwise `Layout::split`
+    // has to squeeze chunks below their requested minimum, which can hand
+    // later expirations a height too small for their border, underflowing
+    // the `- 2` used to compute their inner table area.
     let mut visible_expirations = Vec::new();
     let mut constraints = Vec::new();
-    let mut total_min_height = 0;
-
-    for i in visible_start..visible_end {
-        visible_expirations.push(i);
+    let mut total_min_height: u16 = 0;
 
+    for i in visible_start..expiration_count {
         // Calculate minimum height for this expiration
         let mut height = 3; // Header + border
 
         if app.expanded_expirations[i] {
-            // Add space for options table
-            height += expirations[i].options.len() as u16 + 2; // +2 for table header and padding
+            // Add space for options table, respecting the active fuzzy filter
+            height += app.filtered[i].len() as u16 + 2; // +2 for table header and padding
         }
 
+        // Always show at least one expiration, even if it alone overflows
+        // the area — `Layout::split` will simply give it all of `area.height`.
+        if !visible_expirations.is_empty() && total_min_height + height > area.height {
+            break;
+        }
+
+        visible_expirations.push(i);
         total_min_height += height;
         constraints.push(Constraint::Min(height));
     }
+    let visible_end = visible_start + visible_expirations.len();
 
     // If we have space left, make the last constraint take the remaining space
     if !constraints.is_empty() && total_min_height < area.height {
@@ -282,27 +1138,27 @@ fn render_expirations_list<B: Backend>(f: &mut Frame<B>, app: &App, area: Rect)
 
     // Render visible expirations
     for (chunk_idx, &exp_idx) in visible_expirations.iter().enumerate() {
-        let expiration = &expirations[exp_idx];
+        let expiration_date = app.options_chain.expirations[exp_idx].date.clone();
         let expanded = app.expanded_expirations[exp_idx];
         let prefix = if expanded { "[-] " } else { "[+] " };
 
         // Style based on cursor position
         let style = if exp_idx == app.cursor_position {
             Style::default()
-                .fg(Color::Yellow)
+                .fg(app.theme.cursor)
                 .add_modifier(Modifier::BOLD)
         } else {
-            Style::default().fg(Color::Green)
+            Style::default().fg(app.theme.header)
         };
 
         // Create the expiration header
         let header = Spans::from(vec![Span::styled(
-            format!("{}{}", prefix, expiration.date),
+            format!("{}{}", prefix, expiration_date),
             style,
         )]);
 
         let border_style = if exp_idx == app.cursor_position {
-            Style::default().fg(Color::Yellow)
+            Style::default().fg(app.theme.cursor)
         } else {
             Style::default()
         };
@@ -314,12 +1170,32 @@ fn render_expirations_list<B: Backend>(f: &mut Frame<B>, app: &App, area: Rect)
 
         f.render_widget(expiration_block, chunks[chunk_idx]);
 
-        // If expanded, render the options table inside
-        if expanded {
+        // When expanded, only the top border (where the header title is
+        // drawn) is a hit target for toggling; the rest of the block is the
+        // options table and must stay reachable by `hit_test_option`.
+        // Collapsed blocks have no table underneath, so the whole block
+        // stays clickable.
+        let header_rect = Rect {
+            x: chunks[chunk_idx].x,
+            y: chunks[chunk_idx].y,
+            width: chunks[chunk_idx].width,
+            height: if expanded {
+                1
+            } else {
+                chunks[chunk_idx].height
+            },
+        };
+        app.expiration_hits.push((header_rect, exp_idx));
+
+        // If expanded, render the options table inside, leaving room for
+        // the block's border on all sides. Skip it entirely if the chunk
+        // ended up too small to fit a border plus any content, which can
+        // happen when more is expanded than the terminal has room for.
+        if expanded && chunks[chunk_idx].height > 2 {
             let inner_area = Rect {
                 x: chunks[chunk_idx].x + 1,
                 y: chunks[chunk_idx].y + 1,
-                width: chunks[chunk_idx].width - 2,
+                width: chunks[chunk_idx].width.saturating_sub(2),
                 height: chunks[chunk_idx].height - 2,
             };
 
@@ -328,8 +1204,8 @@ fn render_expirations_list<B: Backend>(f: &mut Frame<B>, app: &App, area: Rect)
     }
 
     // Show scroll indicators if needed
-    if visible_start > 0 || visible_end < expirations.len() {
-        let scroll_text = format!("Scroll: {}/{}", app.cursor_position + 1, expirations.len());
+    if visible_start > 0 || visible_end < expiration_count {
+        let scroll_text = format!("Scroll: {}/{}", app.cursor_position + 1, expiration_count);
         let scroll_text_len = scroll_text.len();
         let scroll_indicator = Spans::from(vec![Span::styled(
             scroll_text,
@@ -349,11 +1225,12 @@ fn render_expirations_list<B: Backend>(f: &mut Frame<B>, app: &App, area: Rect)
 
 fn render_options_table<B: Backend>(
     f: &mut Frame<B>,
-    app: &App,
+    app: &mut App,
     area: Rect,
     expiration_idx: usize,
 ) {
     let current_expiration = &app.options_chain.expirations[expiration_idx];
+    let match_indices = app.filtered[expiration_idx].clone();
 
     // Define table widths based on whether we're showing greeks
     let mut constraints = vec![
@@ -436,15 +1313,70 @@ fn render_options_table<B: Backend>(
         .style(Style::default().fg(Color::White))
         .height(1);
 
-    // Create option rows
-    let rows = current_expiration
-        .options
+    // Create option rows, restricted to the current fuzzy filter matches
+    let rows = match_indices
         .iter()
-        .map(|option| {
+        .map(|&i| (i, &current_expiration.options[i]))
+        .map(|(option_idx, option)| {
+            let call_symbol_style = if app.search_match_set.contains(&(
+                expiration_idx,
+                option_idx,
+                MatchField::CallSymbol,
+            )) {
+                Style::default().add_modifier(Modifier::REVERSED)
+            } else {
+                Style::default()
+            };
+            let put_symbol_style = if app.search_match_set.contains(&(
+                expiration_idx,
+                option_idx,
+                MatchField::PutSymbol,
+            )) {
+                Style::default().add_modifier(Modifier::REVERSED)
+            } else {
+                Style::default()
+            };
+            let strike_matched =
+                app.search_match_set
+                    .contains(&(expiration_idx, option_idx, MatchField::Strike));
+            let flash_style = Style::default()
+                .fg(Color::LightCyan)
+                .add_modifier(Modifier::BOLD);
+            let call_bid_style =
+                if app.is_flashing((expiration_idx, option_idx, CellField::CallBid)) {
+                    flash_style
+                } else {
+                    Style::default()
+                };
+            let call_ask_style =
+                if app.is_flashing((expiration_idx, option_idx, CellField::CallAsk)) {
+                    flash_style
+                } else {
+                    Style::default()
+                };
+            let put_bid_style = if app.is_flashing((expiration_idx, option_idx, CellField::PutBid))
+            {
+                flash_style
+            } else {
+                Style::default()
+            };
+            let put_ask_style = if app.is_flashing((expiration_idx, option_idx, CellField::PutAsk))
+            {
+                flash_style
+            } else {
+                Style::default()
+            };
+
             let mut cells = vec![
-                Cell::from(option.call.symbol.clone()),
-                Cell::from(format!("{:.2}", option.call.bid)),
-                Cell::from(format!("{:.2}", option.call.ask)),
+                Cell::from(Span::styled(option.call.symbol.clone(), call_symbol_style)),
+                Cell::from(Span::styled(
+                    format!("{:.2}", option.call.bid),
+                    call_bid_style,
+                )),
+                Cell::from(Span::styled(
+                    format!("{:.2}", option.call.ask),
+                    call_ask_style,
+                )),
                 Cell::from(option.call.bid_size.to_string()),
                 Cell::from(option.call.ask_size.to_string()),
                 Cell::from(option.call.volume.to_string()),
@@ -460,24 +1392,34 @@ fn render_options_table<B: Backend>(
 
             // Calculate strike color based on relation to current stock price
             let strike_color = if option.strike < app.options_chain.last_price {
-                Color::Green
+                app.theme.itm
             } else if option.strike > app.options_chain.last_price {
-                Color::Red
+                app.theme.otm
             } else {
-                Color::Yellow
+                app.theme.atm
             };
 
+            let mut strike_style = Style::default()
+                .fg(strike_color)
+                .add_modifier(Modifier::BOLD);
+            if strike_matched {
+                strike_style = strike_style.add_modifier(Modifier::REVERSED);
+            }
             cells.push(Cell::from(Span::styled(
                 format!("{:.2}", option.strike),
-                Style::default()
-                    .fg(strike_color)
-                    .add_modifier(Modifier::BOLD),
+                strike_style,
             )));
 
             cells.extend(vec![
-                Cell::from(option.put.symbol.clone()),
-                Cell::from(format!("{:.2}", option.put.bid)),
-                Cell::from(format!("{:.2}", option.put.ask)),
+                Cell::from(Span::styled(option.put.symbol.clone(), put_symbol_style)),
+                Cell::from(Span::styled(
+                    format!("{:.2}", option.put.bid),
+                    put_bid_style,
+                )),
+                Cell::from(Span::styled(
+                    format!("{:.2}", option.put.ask),
+                    put_ask_style,
+                )),
                 Cell::from(option.put.bid_size.to_string()),
                 Cell::from(option.put.ask_size.to_string()),
                 Cell::from(option.put.volume.to_string()),
@@ -505,4 +1447,210 @@ fn render_options_table<B: Backend>(
         .column_spacing(1);
 
     f.render_widget(table, area);
+
+    // Record the on-screen rect of each row for mouse hit-testing: row 0 is
+    // the header, data rows follow one per line starting at area.y + 1.
+    for (row_idx, &option_idx) in match_indices.iter().enumerate() {
+        let y = area.y + 1 + row_idx as u16;
+        if y >= area.y + area.height {
+            break;
+        }
+        let row_rect = Rect {
+            x: area.x,
+            y,
+            width: area.width,
+            height: 1,
+        };
+        app.option_hits.push((row_rect, expiration_idx, option_idx));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzzy_score_empty_query_matches_everything() {
+        assert_eq!(fuzzy_score("", "AAPL240119C00150000"), Some(0));
+    }
+
+    #[test]
+    fn fuzzy_score_requires_chars_in_order() {
+        assert_eq!(fuzzy_score("alc", "AAPL"), None);
+        assert!(fuzzy_score("apl", "AAPL").is_some());
+    }
+
+    #[test]
+    fn fuzzy_score_is_case_insensitive() {
+        assert_eq!(fuzzy_score("aapl", "AAPL"), fuzzy_score("AAPL", "AAPL"));
+    }
+
+    #[test]
+    fn fuzzy_score_rewards_consecutive_matches() {
+        let consecutive = fuzzy_score("ab", "ab").unwrap();
+        let scattered = fuzzy_score("ab", "axb").unwrap();
+        assert!(consecutive > scattered);
+    }
+
+    #[test]
+    fn fuzzy_score_rewards_boundary_matches() {
+        // 'p' lands right after '-' in "150-put", a boundary; in "hopput" it
+        // doesn't, so the former should score higher for the same query.
+        let boundary = fuzzy_score("p", "150-put").unwrap();
+        let no_boundary = fuzzy_score("p", "hopput").unwrap();
+        assert!(boundary > no_boundary);
+    }
+
+    #[test]
+    fn fuzzy_score_digit_to_letter_is_a_boundary() {
+        // 'c' follows a digit in "150c", a boundary; not in "xyzc".
+        let boundary = fuzzy_score("c", "150c").unwrap();
+        let no_boundary = fuzzy_score("c", "xyzc").unwrap();
+        assert!(boundary > no_boundary);
+    }
+
+    #[test]
+    fn parse_key_spec_named_keys() {
+        assert_eq!(
+            parse_key_spec("enter"),
+            Some((KeyCode::Enter, KeyModifiers::NONE))
+        );
+        assert_eq!(
+            parse_key_spec("Esc"),
+            Some((KeyCode::Esc, KeyModifiers::NONE))
+        );
+        assert_eq!(
+            parse_key_spec("PageDown"),
+            Some((KeyCode::PageDown, KeyModifiers::NONE))
+        );
+    }
+
+    #[test]
+    fn parse_key_spec_single_char() {
+        assert_eq!(
+            parse_key_spec("j"),
+            Some((KeyCode::Char('j'), KeyModifiers::NONE))
+        );
+    }
+
+    #[test]
+    fn parse_key_spec_ctrl_prefix() {
+        assert_eq!(
+            parse_key_spec("ctrl-d"),
+            Some((KeyCode::Char('d'), KeyModifiers::CONTROL))
+        );
+        assert_eq!(
+            parse_key_spec("ctrl-enter"),
+            Some((KeyCode::Enter, KeyModifiers::CONTROL))
+        );
+    }
+
+    #[test]
+    fn parse_key_spec_trims_whitespace() {
+        assert_eq!(
+            parse_key_spec("  j  "),
+            Some((KeyCode::Char('j'), KeyModifiers::NONE))
+        );
+    }
+
+    #[test]
+    fn parse_key_spec_rejects_unsupported_multi_char_specs() {
+        assert_eq!(parse_key_spec("foo"), None);
+    }
+
+    fn test_quote(bid: f64, ask: f64) -> OptionData {
+        OptionData {
+            symbol: "TEST".to_string(),
+            bid,
+            ask,
+            bid_size: 1,
+            ask_size: 1,
+            volume: 0,
+            open_interest: 0,
+            greeks: Greeks {
+                delta: 0.0,
+                gamma: 0.0,
+                theta: 0.0,
+                vega: 0.0,
+                rho: 0.0,
+            },
+        }
+    }
+
+    fn test_chain(dates: &[&str]) -> OptionsChain {
+        OptionsChain {
+            symbol: "TEST".to_string(),
+            last_price: 100.0,
+            last_update: String::new(),
+            expirations: dates
+                .iter()
+                .map(|date| Expiration {
+                    date: date.to_string(),
+                    options: vec![OptionPair {
+                        strike: 100.0,
+                        call: test_quote(1.0, 1.1),
+                        put: test_quote(1.0, 1.1),
+                    }],
+                })
+                .collect(),
+        }
+    }
+
+    fn test_app(dates: &[&str]) -> App {
+        App::new(test_chain(dates), Config::default())
+    }
+
+    #[test]
+    fn merge_chain_preserves_expanded_state_by_date() {
+        let mut app = test_app(&["2026-01-01", "2026-02-01"]);
+        app.expanded_expirations[1] = true;
+
+        app.merge_chain(test_chain(&["2026-02-01", "2026-03-01"]));
+
+        // The 2026-02-01 expiration moved from index 1 to index 0; its
+        // expanded state should have followed it rather than staying pinned
+        // to the index.
+        assert_eq!(app.expanded_expirations, vec![true, false]);
+    }
+
+    #[test]
+    fn merge_chain_preserves_cursor_by_date() {
+        let mut app = test_app(&["2026-01-01", "2026-02-01"]);
+        app.cursor_position = 1; // on 2026-02-01
+
+        app.merge_chain(test_chain(&["2026-02-01", "2026-03-01"]));
+
+        assert_eq!(app.cursor_position, 0);
+    }
+
+    #[test]
+    fn merge_chain_clamps_cursor_when_expirations_shrink() {
+        let mut app = test_app(&["2026-01-01", "2026-02-01"]);
+        app.cursor_position = 1;
+
+        app.merge_chain(test_chain(&["2026-03-01"]));
+
+        assert_eq!(app.cursor_position, 0);
+    }
+
+    #[test]
+    fn merge_chain_flags_changed_quotes() {
+        let mut app = test_app(&["2026-01-01"]);
+
+        let mut changed = test_chain(&["2026-01-01"]);
+        changed.expirations[0].options[0].call.bid = 2.0;
+        app.merge_chain(changed);
+
+        assert!(app.cell_flash.contains_key(&(0, 0, CellField::CallBid)));
+        assert!(!app.cell_flash.contains_key(&(0, 0, CellField::CallAsk)));
+    }
+
+    #[test]
+    fn merge_chain_does_not_flag_unchanged_quotes() {
+        let mut app = test_app(&["2026-01-01"]);
+
+        app.merge_chain(test_chain(&["2026-01-01"]));
+
+        assert!(app.cell_flash.is_empty());
+    }
 }